@@ -27,6 +27,9 @@ use parity_scale_codec::{Encode, Decode};
 /// Earlier tranches of validators check first, with later tranches serving as backup.
 pub type DelayTranche = u32;
 
+/// The index of a candidate in the list of candidates fully included as-of a block.
+pub type CandidateIndex = u32;
+
 /// A static context used for all relay-vrf-modulo VRFs.
 pub const RELAY_VRF_MODULO_CONTEXT: &str = "A&V MOD";
 
@@ -97,7 +100,7 @@ pub struct IndirectSignedApprovalVote {
 	/// A block hash where the candidate appears.
 	pub block_hash: Hash,
 	/// The index of the candidate in the list of candidates fully included as-of the block.
-	pub candidate_index: u32,
+	pub candidate_index: CandidateIndex,
 	/// The validator index.
 	pub validator: ValidatorIndex,
 	/// The signature by the validator.